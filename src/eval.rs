@@ -0,0 +1,236 @@
+//! Poker-style hand evaluation for a [`Hand`] of 5–7 cards.
+
+use crate::{Card, CardValue, Hand};
+
+/// The category a 5-card hand falls into, ranked worst to best so that
+/// deriving `Ord` gives the correct poker ordering. Each variant carries the
+/// numeric ranks (2 = Two .. 14 = Ace) needed to break ties within the same
+/// category, most significant first.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum HandRank {
+    HighCard(Vec<u8>),
+    Pair(Vec<u8>),
+    TwoPair(Vec<u8>),
+    ThreeOfAKind(Vec<u8>),
+    Straight(u8),
+    Flush(Vec<u8>),
+    FullHouse(u8, u8),
+    FourOfAKind(Vec<u8>),
+    StraightFlush(u8),
+}
+
+/// Ranks a hand of 5 to 7 cards, choosing the best 5-card subset when more
+/// than 5 are given.
+pub fn evaluate(hand: &Hand) -> HandRank {
+    let cards = hand.cards();
+    let n = cards.len();
+    assert!(
+        (5..=7).contains(&n),
+        "hand evaluation requires 5 to 7 cards, got {}",
+        n
+    );
+    (0u32..(1 << n))
+        .filter(|mask| mask.count_ones() == 5)
+        .map(|mask| {
+            let five: Vec<Card> = (0..n).filter(|i| mask & (1 << i) != 0).map(|i| cards[i]).collect();
+            evaluate_five(&five)
+        })
+        .max()
+        .expect("at least one 5-card subset exists")
+}
+
+fn rank_value(value: CardValue) -> u8 {
+    match value {
+        CardValue::Ace => 14,
+        CardValue::Two => 2,
+        CardValue::Three => 3,
+        CardValue::Four => 4,
+        CardValue::Five => 5,
+        CardValue::Six => 6,
+        CardValue::Seven => 7,
+        CardValue::Eight => 8,
+        CardValue::Nine => 9,
+        CardValue::Ten => 10,
+        CardValue::Jack => 11,
+        CardValue::Queen => 12,
+        CardValue::King => 13,
+        CardValue::Joker => panic!("hand evaluation does not support jokers"),
+    }
+}
+
+/// Ranks exactly 5 cards, bucketing counts per rank (a tally indexed by rank
+/// value 2..=14) to spot
+/// pairs/trips/quads and scanning for 5 consecutive ranks to spot straights,
+/// with the wheel (A-2-3-4-5) treated as a low straight.
+fn evaluate_five(cards: &[Card]) -> HandRank {
+    let mut counts = [0u8; 15];
+    for card in cards {
+        counts[rank_value(card.value()) as usize] += 1;
+    }
+
+    let is_flush = cards.iter().all(|c| c.suit() == cards[0].suit());
+    let mut distinct_ranks: Vec<u8> = (2..=14).filter(|&r| counts[r as usize] > 0).collect();
+    distinct_ranks.sort_unstable_by(|a, b| b.cmp(a));
+    let straight_high = straight_high(&distinct_ranks);
+
+    if let Some(high) = straight_high {
+        if is_flush {
+            return HandRank::StraightFlush(high);
+        }
+    }
+
+    let mut by_count: Vec<(u8, u8)> = (2..=14)
+        .filter(|&r| counts[r as usize] > 0)
+        .map(|r| (counts[r as usize], r))
+        .collect();
+    // Highest count first, ties broken by higher rank first.
+    by_count.sort_unstable_by(|a, b| b.cmp(a));
+
+    if by_count[0].0 == 4 {
+        let quad = by_count[0].1;
+        let kicker = by_count[1].1;
+        return HandRank::FourOfAKind(vec![quad, kicker]);
+    }
+    if by_count[0].0 == 3 && by_count.get(1).map(|&(c, _)| c) == Some(2) {
+        return HandRank::FullHouse(by_count[0].1, by_count[1].1);
+    }
+    if is_flush {
+        return HandRank::Flush(distinct_ranks);
+    }
+    if let Some(high) = straight_high {
+        return HandRank::Straight(high);
+    }
+    if by_count[0].0 == 3 {
+        let trip = by_count[0].1;
+        let kickers: Vec<u8> = by_count[1..].iter().map(|&(_, r)| r).collect();
+        return HandRank::ThreeOfAKind(std::iter::once(trip).chain(kickers).collect());
+    }
+    if by_count[0].0 == 2 && by_count.get(1).map(|&(c, _)| c) == Some(2) {
+        // Already sorted by count then rank descending, so [0] outranks [1].
+        return HandRank::TwoPair(vec![by_count[0].1, by_count[1].1, by_count[2].1]);
+    }
+    if by_count[0].0 == 2 {
+        let pair = by_count[0].1;
+        let kickers: Vec<u8> = by_count[1..].iter().map(|&(_, r)| r).collect();
+        return HandRank::Pair(std::iter::once(pair).chain(kickers).collect());
+    }
+    HandRank::HighCard(distinct_ranks)
+}
+
+/// Given ranks sorted descending with no duplicates, returns the high card of
+/// a 5-consecutive-rank run if one exists, treating Ace as low for the wheel.
+fn straight_high(ranks: &[u8]) -> Option<u8> {
+    if [14, 5, 4, 3, 2].iter().all(|r| ranks.contains(r)) {
+        return Some(5);
+    }
+    if ranks.len() < 5 {
+        return None;
+    }
+    for window in ranks.windows(5) {
+        if window[0] - window[4] == 4 {
+            return Some(window[0]);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CardValue::*;
+    use crate::Suit::*;
+    use crate::{Card, Hand};
+
+    fn hand(cards: Vec<Card>) -> Hand {
+        Hand::new(cards)
+    }
+
+    #[test]
+    fn ranks_high_card() {
+        let h = hand(vec![
+            Card::new(Two, Spades),
+            Card::new(Five, Hearts),
+            Card::new(Nine, Clubs),
+            Card::new(Jack, Diamonds),
+            Card::new(King, Spades),
+        ]);
+        assert!(matches!(evaluate(&h), HandRank::HighCard(_)));
+    }
+
+    #[test]
+    fn ranks_pair_above_high_card() {
+        let pair = hand(vec![
+            Card::new(Two, Spades),
+            Card::new(Two, Hearts),
+            Card::new(Nine, Clubs),
+            Card::new(Jack, Diamonds),
+            Card::new(King, Spades),
+        ]);
+        let high_card = hand(vec![
+            Card::new(Three, Spades),
+            Card::new(Five, Hearts),
+            Card::new(Nine, Clubs),
+            Card::new(Jack, Diamonds),
+            Card::new(King, Spades),
+        ]);
+        assert!(evaluate(&pair) > evaluate(&high_card));
+    }
+
+    #[test]
+    fn detects_wheel_straight() {
+        let wheel = hand(vec![
+            Card::new(Ace, Spades),
+            Card::new(Two, Hearts),
+            Card::new(Three, Clubs),
+            Card::new(Four, Diamonds),
+            Card::new(Five, Spades),
+        ]);
+        assert_eq!(evaluate(&wheel), HandRank::Straight(5));
+    }
+
+    #[test]
+    fn detects_straight_flush() {
+        let sf = hand(vec![
+            Card::new(Five, Spades),
+            Card::new(Six, Spades),
+            Card::new(Seven, Spades),
+            Card::new(Eight, Spades),
+            Card::new(Nine, Spades),
+        ]);
+        assert_eq!(evaluate(&sf), HandRank::StraightFlush(9));
+    }
+
+    #[test]
+    fn best_five_of_seven_is_chosen() {
+        let h = hand(vec![
+            Card::new(Two, Spades),
+            Card::new(Two, Hearts),
+            Card::new(Two, Clubs),
+            Card::new(Two, Diamonds),
+            Card::new(King, Spades),
+            Card::new(Queen, Hearts),
+            Card::new(Jack, Clubs),
+        ]);
+        assert!(matches!(evaluate(&h), HandRank::FourOfAKind(_)));
+    }
+
+    #[test]
+    fn max_over_several_hands_yields_winner() {
+        let low = hand(vec![
+            Card::new(Two, Spades),
+            Card::new(Five, Hearts),
+            Card::new(Nine, Clubs),
+            Card::new(Jack, Diamonds),
+            Card::new(King, Spades),
+        ]);
+        let full_house = hand(vec![
+            Card::new(Three, Spades),
+            Card::new(Three, Hearts),
+            Card::new(Three, Clubs),
+            Card::new(Jack, Diamonds),
+            Card::new(Jack, Spades),
+        ]);
+        let best = [evaluate(&low), evaluate(&full_house)].into_iter().max().unwrap();
+        assert!(matches!(best, HandRank::FullHouse(_, _)));
+    }
+}
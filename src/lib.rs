@@ -3,14 +3,94 @@ use std::str::FromStr;
 use crate::CardValue::*;
 use crate::Suit::*;
 use rand::rng;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
+pub mod eval;
+pub use eval::HandRank;
+
+#[derive(Debug)]
 pub struct Deck {
     deck: Vec<Card>,
     shuffled: bool,
+    seed: Option<u64>,
+}
+
+/// A collection of cards held by a single player, dealt off the top of a [`Deck`].
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Hand(Vec<Card>);
+
+impl Hand {
+    pub fn new(cards: Vec<Card>) -> Hand {
+        Hand(cards)
+    }
+    pub fn cards(&self) -> &Vec<Card> {
+        &self.0
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Scores the hand under blackjack rules: face cards count as 10, and
+    /// Aces count as 11 but are demoted to 1 one at a time to avoid busting.
+    /// Returns the best total at or under 21, or the minimal bust total if
+    /// every combination of Ace values still busts.
+    pub fn blackjack_value(&self) -> u8 {
+        let mut total: u32 = 0;
+        let mut soft_aces = 0u32;
+        for card in self.0.iter() {
+            let (value, is_ace) = blackjack_card_value(card.value());
+            total += value as u32;
+            if is_ace {
+                soft_aces += 1;
+            }
+        }
+        while total > 21 && soft_aces > 0 {
+            total -= 10;
+            soft_aces -= 1;
+        }
+        total as u8
+    }
+    /// A two-card hand totalling 21.
+    pub fn is_blackjack(&self) -> bool {
+        self.0.len() == 2 && self.blackjack_value() == 21
+    }
+    pub fn is_bust(&self) -> bool {
+        self.blackjack_value() > 21
+    }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+/// The blackjack pip value of a rank, and whether it's an Ace (so it can be
+/// demoted from 11 to 1 when the hand would otherwise bust).
+fn blackjack_card_value(value: CardValue) -> (u8, bool) {
+    match value {
+        CardValue::Ace => (11, true),
+        CardValue::Two => (2, false),
+        CardValue::Three => (3, false),
+        CardValue::Four => (4, false),
+        CardValue::Five => (5, false),
+        CardValue::Six => (6, false),
+        CardValue::Seven => (7, false),
+        CardValue::Eight => (8, false),
+        CardValue::Nine => (9, false),
+        CardValue::Ten | CardValue::Jack | CardValue::Queen | CardValue::King => (10, false),
+        CardValue::Joker => panic!("blackjack scoring does not support jokers"),
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in self.0.iter() {
+            write!(f, "{}", card)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub struct Card {
     value: CardValue,
     suit: Suit,
@@ -31,60 +111,81 @@ pub enum CardValue {
     Jack,
     Queen,
     King,
+    Joker,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 pub enum Suit {
     Hearts,
     Diamonds,
     Clubs,
     Spades,
+    Joker,
 }
 
-impl From<char> for Suit {
-    fn from(char: char) -> Suit {
-        match char {
-            'H' => Hearts,
-            'D' => Diamonds,
-            'C' => Clubs,
-            'S' => Spades,
-            _ => Spades,
+/// Whether a constructed [`Deck`] should include jokers, and if so how many.
+pub enum WithOrWithoutJokers {
+    Without,
+    With(usize),
+}
+
+impl TryFrom<char> for Suit {
+    type Error = String;
+    fn try_from(char: char) -> Result<Suit, Self::Error> {
+        match char.to_ascii_uppercase() {
+            'H' => Ok(Hearts),
+            'D' => Ok(Diamonds),
+            'C' => Ok(Clubs),
+            'S' => Ok(Spades),
+            'X' => Ok(Suit::Joker),
+            other => Err(format!("'{}' is not a valid suit", other)),
         }
     }
 }
 
-impl From<char> for CardValue {
-    fn from(char: char) -> CardValue {
-        match char {
-            'A' => Ace,
-            '2' => Two,
-            '3' => Three,
-            '4' => Four,
-            '5' => Five,
-            '6' => Six,
-            '7' => Seven,
-            '8' => Eight,
-            '9' => Nine,
-            'T' => Ten,
-            'J' => Jack,
-            'Q' => Queen,
-            'K' => King,
-            _ => Ace,
+impl TryFrom<char> for CardValue {
+    type Error = String;
+    fn try_from(char: char) -> Result<CardValue, Self::Error> {
+        match char.to_ascii_uppercase() {
+            'A' => Ok(Ace),
+            '2' => Ok(Two),
+            '3' => Ok(Three),
+            '4' => Ok(Four),
+            '5' => Ok(Five),
+            '6' => Ok(Six),
+            '7' => Ok(Seven),
+            '8' => Ok(Eight),
+            '9' => Ok(Nine),
+            'T' => Ok(Ten),
+            'J' => Ok(Jack),
+            'Q' => Ok(Queen),
+            'K' => Ok(King),
+            'X' => Ok(CardValue::Joker),
+            other => Err(format!("'{}' is not a valid rank", other)),
         }
     }
 }
 impl Display for Deck {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for i in self.deck.iter() {
-            write!(f, "{}", i)?;
+        if f.alternate() {
+            let cards: Vec<String> = self.deck.iter().map(|c| format!("{:#}", c)).collect();
+            write!(f, "{}", cards.join(", "))
+        } else {
+            for i in self.deck.iter() {
+                write!(f, "{}", i)?;
+            }
+            Ok(())
         }
-        Ok(())
     }
 }
 
 impl Display for Card {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.value, self.suit)
+        if f.alternate() {
+            write!(f, "{}{:#}", self.value, self.suit)
+        } else {
+            write!(f, "{}{}", self.value, self.suit)
+        }
     }
 }
 
@@ -104,50 +205,99 @@ impl Display for CardValue {
             Jack => "J",
             Queen => "Q",
             King => "K",
+            CardValue::Joker => "X",
         })
     }
 }
 
+impl CardValue {
+    /// The value's full English name, e.g. "Ace" or "Ten".
+    pub fn name(&self) -> String {
+        match self {
+            Ace => "Ace",
+            Two => "Two",
+            Three => "Three",
+            Four => "Four",
+            Five => "Five",
+            Six => "Six",
+            Seven => "Seven",
+            Eight => "Eight",
+            Nine => "Nine",
+            Ten => "Ten",
+            Jack => "Jack",
+            Queen => "Queen",
+            King => "King",
+            CardValue::Joker => "Joker",
+        }
+        .to_string()
+    }
+}
+
 impl Display for Suit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", match self {
-            Hearts => "H",
-            Diamonds => "D",
-            Clubs => "C",
-            Spades => "S",
-        })
+        if f.alternate() {
+            write!(f, "{}", match self {
+                Hearts => "\u{2665}",
+                Diamonds => "\u{2666}",
+                Clubs => "\u{2663}",
+                Spades => "\u{2660}",
+                Suit::Joker => "\u{1F0DF}",
+            })
+        } else {
+            write!(f, "{}", match self {
+                Hearts => "H",
+                Diamonds => "D",
+                Clubs => "C",
+                Spades => "S",
+                Suit::Joker => "X",
+            })
+        }
+    }
+}
+
+impl Suit {
+    /// The suit's full English name, e.g. "Hearts" or "Joker".
+    pub fn name(&self) -> String {
+        match self {
+            Hearts => "Hearts",
+            Diamonds => "Diamonds",
+            Clubs => "Clubs",
+            Spades => "Spades",
+            Suit::Joker => "Joker",
+        }
+        .to_string()
     }
 }
 
 impl Default for Deck {
     fn default() -> Self {
-        Self::new()
+        Self::new(WithOrWithoutJokers::Without)
     }
 }
 impl FromStr for Deck {
     type Err = String;
     fn from_str(input: &str) -> Result<Deck, Self::Err> {
-        let mut deck = Vec::new();
-        let mut input = input.bytes();
-        loop {
-            let rank: char = match input.next(){
-                Some(b) => b.into(),
-                None => Err(String::from("Invalid rank in deck"))?,
-            };
-            let suit: char = match input.next(){
-                Some(b) => b.into(),
-                None => Err(String::from("Invalid Suit in deck"))?,
-            };
-            deck.push(Card::new(rank.into(), suit.into()));
-            if input.len() == 0 {
-                break Ok(Deck { deck, shuffled: true })
-            }
+        let bytes = input.as_bytes();
+        if !bytes.len().is_multiple_of(2) {
+            return Err(format!(
+                "dangling rank with no suit at position {}",
+                bytes.len() - 1
+            ));
+        }
+        let mut deck = Vec::with_capacity(bytes.len() / 2);
+        for (i, pair) in bytes.chunks(2).enumerate() {
+            let rank = CardValue::try_from(pair[0] as char)
+                .map_err(|e| format!("{} at position {}", e, i * 2))?;
+            let suit = Suit::try_from(pair[1] as char)
+                .map_err(|e| format!("{} at position {}", e, i * 2 + 1))?;
+            deck.push(Card::new(rank, suit));
         }
+        Ok(Deck { deck, shuffled: true, seed: None })
     }
 }
 
 impl Deck {
-    pub fn new() -> Deck {
+    pub fn new(jokers: WithOrWithoutJokers) -> Deck {
         let mut deck = Vec::new();
         const SUITS: [Suit; 4] = [Spades, Diamonds, Clubs, Hearts];
         const VALUES: [CardValue; 13] = [
@@ -161,47 +311,156 @@ impl Deck {
                 })
             }
         }
+        if let WithOrWithoutJokers::With(count) = jokers {
+            for _ in 0..count {
+                deck.push(Card::new_joker());
+            }
+        }
         Deck {
             deck,
             shuffled: false,
+            seed: None,
         }
     }
+    /// Convenience constructor for a standard deck plus `count` jokers, e.g.
+    /// `Deck::new_with_jokers(2)` for the common 54-card case.
+    pub fn new_with_jokers(count: usize) -> Deck {
+        Deck::new(WithOrWithoutJokers::With(count))
+    }
     pub fn new_shuffled() -> Deck {
-        let mut deck = Deck::new();
+        let mut deck = Deck::new(WithOrWithoutJokers::Without);
         let mut rng = rng();
         deck.deck.shuffle(&mut rng);
         deck.shuffled = true;
         deck
     }
+    /// Builds a standard deck already shuffled with a seeded PRNG, so the
+    /// exact order can be reproduced later by passing the same `seed` again.
+    pub fn new_shuffled_seeded(seed: u64) -> Deck {
+        let mut deck = Deck::new(WithOrWithoutJokers::Without);
+        deck.shuffle_seeded(seed);
+        deck
+    }
     pub fn new_custom (deck: Vec<Card>) -> Deck {
-        Deck { deck, shuffled: false }
+        Deck { deck, shuffled: false, seed: None }
+    }
+    /// Parses a deck the old, permissive way: an unrecognized rank or suit
+    /// character silently falls back to Ace/Spades instead of erroring.
+    /// Prefer the strict `FromStr` impl (`"...".parse::<Deck>()`) unless a
+    /// caller genuinely needs to tolerate garbage input.
+    pub fn parse_lenient(input: &str) -> Result<Deck, String> {
+        fn lenient_rank(char: char) -> CardValue {
+            CardValue::try_from(char).unwrap_or(Ace)
+        }
+        fn lenient_suit(char: char) -> Suit {
+            Suit::try_from(char).unwrap_or(Spades)
+        }
+        let mut deck = Vec::new();
+        let mut input = input.bytes();
+        loop {
+            let rank = match input.next() {
+                Some(b) => lenient_rank(b as char),
+                None => return Err(String::from("Invalid rank in deck")),
+            };
+            let suit = match input.next() {
+                Some(b) => lenient_suit(b as char),
+                None => return Err(String::from("Invalid Suit in deck")),
+            };
+            deck.push(Card::new(rank, suit));
+            if input.len() == 0 {
+                break Ok(Deck { deck, shuffled: true, seed: None });
+            }
+        }
     }
     pub fn shuffle(&mut self) {
         self.shuffled = true;
+        self.seed = None;
         let mut rng = rng();
         self.deck.shuffle(&mut rng);
     }
+    /// Shuffles the deck with a seeded PRNG instead of the thread RNG, so the
+    /// resulting order can be replayed later by calling this again with the
+    /// same `seed`. The seed used is recorded and available via [`Deck::seed`].
+    pub fn shuffle_seeded(&mut self, seed: u64) {
+        self.shuffled = true;
+        self.seed = Some(seed);
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.deck.shuffle(&mut rng);
+    }
     pub fn deck(&self) -> &Vec<Card> {
         &self.deck
     }
     pub fn shuffled(&self) -> bool {
         self.shuffled
     }
+    /// The seed of the shuffle that produced the current order, or `None` if
+    /// the deck was never seeded-shuffled or was last shuffled unseeded.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
     pub fn size(&self) -> usize {
         self.deck.len()
     }
+    /// Removes the top `n` cards from the deck and returns them as a [`Hand`].
+    ///
+    /// Errors if fewer than `n` cards remain so callers don't have to check
+    /// `size()` themselves before drawing.
+    pub fn draw(&mut self, n: usize) -> Result<Hand, String> {
+        if n > self.deck.len() {
+            return Err(format!(
+                "cannot draw {} cards from a deck of {}",
+                n,
+                self.deck.len()
+            ));
+        }
+        Ok(Hand(self.deck.drain(0..n).collect()))
+    }
+    /// Deals `cards_each` cards to each of `players`, round-robin style, the
+    /// way a real dealer passes one card to each player at a time.
+    pub fn deal(&mut self, players: usize, cards_each: usize) -> Result<Vec<Hand>, String> {
+        let needed = players * cards_each;
+        if needed > self.deck.len() {
+            return Err(format!(
+                "cannot deal {} cards to {} players from a deck of {}",
+                cards_each,
+                players,
+                self.deck.len()
+            ));
+        }
+        let mut hands = vec![Vec::with_capacity(cards_each); players];
+        for _ in 0..cards_each {
+            for hand in hands.iter_mut() {
+                hand.push(self.deck.remove(0));
+            }
+        }
+        Ok(hands.into_iter().map(Hand).collect())
+    }
 }
 
 impl Card {
     pub fn new(value: CardValue, suit: Suit) -> Card {
         Card { value, suit }
     }
+    pub fn new_joker() -> Card {
+        Card { value: CardValue::Joker, suit: Suit::Joker }
+    }
     pub fn value(&self) -> CardValue {
         self.value
     }
     pub fn suit(&self) -> Suit {
         self.suit
     }
+    pub fn is_joker(&self) -> bool {
+        self.value == CardValue::Joker
+    }
+    /// The card's full English name, e.g. "Ace of Spades" or "Joker".
+    pub fn name(&self) -> String {
+        if self.is_joker() {
+            "Joker".to_string()
+        } else {
+            format!("{} of {}", self.value.name(), self.suit.name())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -209,7 +468,7 @@ mod tests {
     use super::*;
     #[test]
     fn shuffled_values() {
-        let mut deck = Deck::new();
+        let mut deck = Deck::new(WithOrWithoutJokers::Without);
         let shuffled_deck = Deck::new_shuffled();
         assert!(!deck.shuffled);
         assert!(shuffled_deck.shuffled);
@@ -218,10 +477,116 @@ mod tests {
     }
     #[test]
     fn deck_size() {
-        let deck = Deck::new();
+        let deck = Deck::new(WithOrWithoutJokers::Without);
         assert_eq!(deck.deck.len(), 52)
     }
     #[test]
+    fn deck_with_two_jokers_has_54_cards() {
+        let deck = Deck::new_with_jokers(2);
+        assert_eq!(deck.size(), 54);
+        assert_eq!(deck.deck.iter().filter(|c| c.is_joker()).count(), 2);
+    }
+    #[test]
+    fn joker_parses_from_token() {
+        let deck: Deck = "XX2C".parse().unwrap();
+        assert!(deck.deck[0].is_joker());
+        assert_eq!(deck.deck[1], Card::new(Two, Clubs));
+    }
+    #[test]
+    fn seeded_shuffle_is_reproducible() {
+        let a = Deck::new_shuffled_seeded(42);
+        let b = Deck::new_shuffled_seeded(42);
+        assert_eq!(a.deck, b.deck);
+        assert_eq!(a.seed(), Some(42));
+    }
+    #[test]
+    fn seeded_shuffle_differs_by_seed() {
+        let a = Deck::new_shuffled_seeded(1);
+        let b = Deck::new_shuffled_seeded(2);
+        assert_ne!(a.deck, b.deck);
+    }
+    #[test]
+    fn unseeded_shuffle_clears_seed() {
+        let mut deck = Deck::new_shuffled_seeded(42);
+        deck.shuffle();
+        assert_eq!(deck.seed(), None);
+    }
+    #[test]
+    fn card_alternate_printing_uses_suit_glyph() {
+        assert_eq!(format!("{:#}", Card::new(Ace, Spades)), "A\u{2660}");
+    }
+    #[test]
+    fn card_name_is_long_form() {
+        assert_eq!(Card::new(Ace, Spades).name(), "Ace of Spades");
+        assert_eq!(Card::new_joker().name(), "Joker");
+    }
+    #[test]
+    fn deck_alternate_printing_has_separators() {
+        let deck: Deck = "AS2C".parse().unwrap();
+        assert_eq!(format!("{:#}", deck), "A\u{2660}, 2\u{2663}");
+    }
+    #[test]
+    fn strict_parse_rejects_bad_rank() {
+        let err = "ZZ".parse::<Deck>().unwrap_err();
+        assert!(err.contains("not a valid rank") && err.contains("position 0"));
+    }
+    #[test]
+    fn strict_parse_rejects_dangling_rank() {
+        let err = "AS2".parse::<Deck>().unwrap_err();
+        assert!(err.contains("position 2"));
+    }
+    #[test]
+    fn strict_parse_is_case_insensitive() {
+        let deck: Deck = "as2c".parse().unwrap();
+        assert_eq!(deck.deck[0], Card::new(Ace, Spades));
+        assert_eq!(deck.deck[1], Card::new(Two, Clubs));
+    }
+    #[test]
+    fn parse_lenient_falls_back_on_garbage() {
+        let deck = Deck::parse_lenient("ZZ").unwrap();
+        assert_eq!(deck.deck[0], Card::new(Ace, Spades));
+    }
+    #[test]
+    fn blackjack_values_face_and_number_cards() {
+        let hand = Hand::new(vec![Card::new(King, Hearts), Card::new(Seven, Spades)]);
+        assert_eq!(hand.blackjack_value(), 17);
+    }
+    #[test]
+    fn blackjack_ace_counts_as_eleven_when_safe() {
+        let hand = Hand::new(vec![Card::new(Ace, Hearts), Card::new(King, Spades)]);
+        assert_eq!(hand.blackjack_value(), 21);
+        assert!(hand.is_blackjack());
+    }
+    #[test]
+    fn blackjack_ace_demotes_to_avoid_bust() {
+        let hand = Hand::new(vec![
+            Card::new(Ace, Hearts),
+            Card::new(King, Spades),
+            Card::new(Five, Clubs),
+        ]);
+        assert_eq!(hand.blackjack_value(), 16);
+        assert!(!hand.is_bust());
+    }
+    #[test]
+    fn blackjack_multiple_aces() {
+        let hand = Hand::new(vec![
+            Card::new(Ace, Hearts),
+            Card::new(Ace, Spades),
+            Card::new(Nine, Clubs),
+        ]);
+        assert_eq!(hand.blackjack_value(), 21);
+    }
+    #[test]
+    fn blackjack_bust_reports_minimal_total() {
+        let hand = Hand::new(vec![
+            Card::new(King, Hearts),
+            Card::new(Queen, Spades),
+            Card::new(Five, Clubs),
+        ]);
+        assert_eq!(hand.blackjack_value(), 25);
+        assert!(hand.is_bust());
+    }
+    #[test]
     fn card_value_printing() {
         assert_eq!(format!("{}", Card::new(Ace, Spades)), "AS");
         assert_eq!(format!("{}", Card::new(Ace, Hearts)), "AH");
@@ -235,4 +600,31 @@ mod tests {
         assert_eq!(deck.deck[2], Card::new(Five, Diamonds));
         assert_eq!(deck.deck[3], Card::new(Ten, Hearts));
     }
+    #[test]
+    fn draw_removes_from_top() {
+        let mut deck: Deck = "AS2C5DTH".parse().unwrap();
+        let hand = deck.draw(2).unwrap();
+        assert_eq!(hand.cards(), &vec![Card::new(Ace, Spades), Card::new(Two, Clubs)]);
+        assert_eq!(deck.size(), 2);
+    }
+    #[test]
+    fn draw_too_many_errors() {
+        let mut deck: Deck = "AS2C".parse().unwrap();
+        assert!(deck.draw(3).is_err());
+    }
+    #[test]
+    fn deal_round_robins_cards() {
+        let mut deck: Deck = "AS2C5DTHJHQS".parse().unwrap();
+        let hands = deck.deal(3, 2).unwrap();
+        assert_eq!(hands.len(), 3);
+        assert_eq!(hands[0].cards(), &vec![Card::new(Ace, Spades), Card::new(Ten, Hearts)]);
+        assert_eq!(hands[1].cards(), &vec![Card::new(Two, Clubs), Card::new(Jack, Hearts)]);
+        assert_eq!(hands[2].cards(), &vec![Card::new(Five, Diamonds), Card::new(Queen, Spades)]);
+        assert_eq!(deck.size(), 0);
+    }
+    #[test]
+    fn deal_too_many_errors() {
+        let mut deck: Deck = "AS2C".parse().unwrap();
+        assert!(deck.deal(3, 2).is_err());
+    }
 }